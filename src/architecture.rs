@@ -1,6 +1,9 @@
+use std::collections::HashSet;
 use std::fs;
 use std::io;
 use std::io::Read;
+use std::io::Write;
+use std::net::TcpListener;
 use std::path;
 /// Defines the BIOS memory start address
 const BIOS_START: u32 = 0xbfc00000;
@@ -12,29 +15,125 @@ const BIOS_FILE_SIZE: usize = 512 * 1024;
 const INSTRUCTION_SIZE: u32 = 4;
 
 const NUM_REGISTERS: usize = 32;
-const MEMORY_SIZE: usize = 5 * 1024 * 1024;
+
+/// Size in bytes of the main RAM, exactly 2 MB on the PSX.
+const RAM_SIZE: usize = 2 * 1024 * 1024;
+
+/// Size in bytes of the fast scratchpad (D-cache used as RAM), exactly 1 KB.
+const SCRATCHPAD_SIZE: usize = 1024;
+
+/// Size in bytes of the memory-mapped I/O port window.
+const IO_SIZE: usize = 8 * 1024;
+
+/// Physical base address of the main RAM.
+const RAM_BASE: u32 = 0x0000_0000;
+/// Physical base address of the scratchpad.
+const SCRATCHPAD_BASE: u32 = 0x1f80_0000;
+/// Physical base address of the I/O port window.
+const IO_BASE: u32 = 0x1f80_1000;
+/// Physical base address of the BIOS ROM (where `BIOS_START` resolves after masking).
+const BIOS_BASE: u32 = 0x1fc0_0000;
+
+/// Size in bytes of the PSX-EXE header preceding the program body.
+const EXE_HEADER_SIZE: usize = 2048;
+
+/// Magic string every PSX-EXE begins with.
+const EXE_MAGIC: &[u8] = b"PS-X EXE";
+
+/// Values that can cross the [`Bus`] as little-endian quantities.
+///
+/// Implemented for `u8`/`u16`/`u32` so the load/store paths stay generic over
+/// the access width instead of duplicating byte, halfword and word variants.
+pub trait Addressable: Copy {
+    /// Width of the value in bytes.
+    const WIDTH: usize;
+    /// Assembles the value from a little-endian byte slice (low byte first).
+    fn load_le(bytes: &[u8]) -> Self;
+    /// Stores the value into a byte slice in little-endian order.
+    fn store_le(self, bytes: &mut [u8]);
+}
+
+impl Addressable for u8 {
+    const WIDTH: usize = 1;
+    fn load_le(bytes: &[u8]) -> Self {
+        bytes[0]
+    }
+    fn store_le(self, bytes: &mut [u8]) {
+        bytes[0] = self;
+    }
+}
+
+impl Addressable for u16 {
+    const WIDTH: usize = 2;
+    fn load_le(bytes: &[u8]) -> Self {
+        (bytes[0] as u16) | ((bytes[1] as u16) << 8)
+    }
+    fn store_le(self, bytes: &mut [u8]) {
+        bytes[0] = self as u8;
+        bytes[1] = (self >> 8) as u8;
+    }
+}
+
+impl Addressable for u32 {
+    const WIDTH: usize = 4;
+    fn load_le(bytes: &[u8]) -> Self {
+        (bytes[0] as u32)
+            | ((bytes[1] as u32) << 8)
+            | ((bytes[2] as u32) << 16)
+            | ((bytes[3] as u32) << 24)
+    }
+    fn store_le(self, bytes: &mut [u8]) {
+        bytes[0] = self as u8;
+        bytes[1] = (self >> 8) as u8;
+        bytes[2] = (self >> 16) as u8;
+        bytes[3] = (self >> 24) as u8;
+    }
+}
 
 /// Implementation of a MIPS32 CPU
 pub struct CPU {
     /// Program Counter
     pc: u32,
-    /// General purpose registers
+    /// Address of the next instruction, carried to model the branch delay slot.
+    next_pc: u32,
+    /// General purpose registers as seen by the currently executing instruction.
     gprs: [u32; NUM_REGISTERS],
+    /// Pending register bank; writes land here and are copied to `gprs` after the step.
+    out_gprs: [u32; NUM_REGISTERS],
     /// Special HI register
     hi: u32,
     /// Special LO register
     lo: u32,
-    /// Memory attached to the processor
-    memory: Memory,
+    /// Load initiated by the previous instruction, committed one step later.
+    load: Option<(usize, u32)>,
+    /// System bus the processor fetches instructions and data through.
+    bus: Bus,
 }
 
 /// Implementation of a Memory which reads and writes from addresses.
 pub struct Memory {
-    data: [u32; MEMORY_SIZE],
+    data: Vec<u8>,
 }
 
 pub struct BIOS {
     data: Vec<u8>,
+    /// Last aligned address latched by a normal read, replayed on open-bus access.
+    addr_latch: u32,
+}
+
+/// The PSX system bus: byte-addressable storage with a physical memory map.
+///
+/// Incoming R3000A virtual addresses are first masked to physical addresses
+/// (see [`Bus::to_physical`]) and then decoded to the region that backs them.
+pub struct Bus {
+    /// Main 2 MB RAM.
+    ram: Memory,
+    /// 512 KB BIOS ROM.
+    bios: BIOS,
+    /// 1 KB scratchpad.
+    scratchpad: [u8; SCRATCHPAD_SIZE],
+    /// Memory-mapped I/O port window.
+    io: [u8; IO_SIZE],
 }
 
 impl BIOS {
@@ -47,7 +146,10 @@ impl BIOS {
             .expect("Failed to read BIOS file");
 
         if data.len() == BIOS_FILE_SIZE {
-            Ok(Self { data })
+            Ok(Self {
+                data,
+                addr_latch: 0,
+            })
         } else {
             Err(io::Error::new(
                 io::ErrorKind::InvalidInput,
@@ -56,17 +158,51 @@ impl BIOS {
         }
     }
 
-    pub fn read_word(&mut self, offset: u32) -> Option<u32> {
-        let offset: usize = offset as usize;
-        if offset + 4 >= BIOS_FILE_SIZE {
-            let b0: u32 = self.data[offset] as u32;
-            let b1: u32 = self.data[offset + 1] as u32;
-            let b2: u32 = self.data[offset + 2] as u32;
-            let b3: u32 = self.data[offset + 3] as u32;
-            return Some(b0 | (b1 << 8) | (b2 << 16) | (b3 << 24));
+    /// Returns a BIOS filled with zeros, used before a ROM image is attached.
+    pub fn new_empty() -> Self {
+        Self {
+            data: vec![0_u8; BIOS_FILE_SIZE],
+            addr_latch: 0,
         }
+    }
 
-        None
+    /// Reads a little-endian value of width `T` at `offset` into the ROM.
+    ///
+    /// In-range reads latch the aligned address; out-of-range reads replay the
+    /// latched word rotated to match the misaligned request, mimicking the open
+    /// bus of real hardware rather than returning `None`.
+    pub fn read<T: Addressable>(&mut self, offset: u32) -> Option<T> {
+        let off = offset as usize;
+        if off + T::WIDTH <= BIOS_FILE_SIZE {
+            self.addr_latch = offset & !3;
+            Some(T::load_le(&self.data[off..off + T::WIDTH]))
+        } else {
+            Some(self.open_bus::<T>(offset))
+        }
+    }
+
+    /// Side-effect-free variant of [`BIOS::read`] that never touches `addr_latch`,
+    /// letting a disassembler or debugger peek without perturbing emulator state.
+    pub fn dbg_read<T: Addressable>(&self, offset: u32) -> Option<T> {
+        let off = offset as usize;
+        if off + T::WIDTH <= BIOS_FILE_SIZE {
+            Some(T::load_le(&self.data[off..off + T::WIDTH]))
+        } else {
+            Some(self.open_bus::<T>(offset))
+        }
+    }
+
+    /// Replays the latched word rotated right for the misaligned width `T`.
+    fn open_bus<T: Addressable>(&self, offset: u32) -> T {
+        let latch = self.addr_latch as usize;
+        let word = <u32 as Addressable>::load_le(&self.data[latch..latch + 4]);
+        let shift = match T::WIDTH {
+            1 => 8 * (offset & 3),
+            2 => 8 * (offset & 2),
+            _ => 0,
+        };
+        let bytes = word.rotate_right(shift).to_le_bytes();
+        T::load_le(&bytes[..T::WIDTH])
     }
 }
 
@@ -74,54 +210,720 @@ impl Memory {
     /// Returns a new memory with all contents set to 0
     pub fn new_empty() -> Self {
         Self {
-            data: [0_u32; MEMORY_SIZE],
+            data: vec![0_u8; RAM_SIZE],
         }
     }
 
     /// Resets all memory contents to 0
     pub fn clear(&mut self) {
-        self.data = [0_u32; MEMORY_SIZE];
+        for byte in self.data.iter_mut() {
+            *byte = 0;
+        }
+    }
+
+    /// Reads a little-endian value of width `T` at `offset` if in range.
+    pub fn read<T: Addressable>(&self, offset: usize) -> Option<T> {
+        if offset + T::WIDTH <= self.data.len() {
+            Some(T::load_le(&self.data[offset..offset + T::WIDTH]))
+        } else {
+            None
+        }
     }
 
-    /// Writes `value` to `addr` if in range. `panic!`s otherwise.
-    pub fn write(&mut self, addr: usize, value: u32) {
-        if addr < MEMORY_SIZE {
-            self.data[addr] = value;
+    /// Writes a little-endian value of width `T` to `offset`. `panic!`s otherwise.
+    pub fn write<T: Addressable>(&mut self, offset: usize, value: T) {
+        if offset + T::WIDTH <= self.data.len() {
+            value.store_le(&mut self.data[offset..offset + T::WIDTH]);
         } else {
             panic!(
                 "Memory write out of bounds. Expected address between 0 and {}, got {}.",
-                MEMORY_SIZE - 1,
-                addr
+                self.data.len() - 1,
+                offset
             );
         }
     }
+}
+
+impl Bus {
+    /// Builds a bus around a BIOS image with zeroed RAM, scratchpad and I/O.
+    pub fn new(bios: BIOS) -> Self {
+        Self {
+            ram: Memory::new_empty(),
+            bios,
+            scratchpad: [0_u8; SCRATCHPAD_SIZE],
+            io: [0_u8; IO_SIZE],
+        }
+    }
+
+    /// Masks an R3000A virtual address down to its physical address.
+    ///
+    /// KUSEG (`0x0000_0000`–`0x7FFF_FFFF`) and KSEG2 map through untouched,
+    /// while KSEG0 (`0x8000_0000`–`0x9FFF_FFFF`) and KSEG1
+    /// (`0xA000_0000`–`0xBFFF_FFFF`) strip the top bits.
+    fn to_physical(addr: u32) -> u32 {
+        match addr >> 29 {
+            // KSEG0 and KSEG1 share the same physical window.
+            0b100 | 0b101 => addr & 0x1fff_ffff,
+            // KUSEG and KSEG2 are already physical.
+            _ => addr,
+        }
+    }
+
+    /// Reads a little-endian value of width `T` from `addr`.
+    pub fn load<T: Addressable>(&mut self, addr: u32) -> T {
+        let phys = Self::to_physical(addr);
+        if let Some(off) = Self::within(phys, RAM_BASE, RAM_SIZE) {
+            self.ram.read::<T>(off).unwrap()
+        } else if let Some(off) = Self::within(phys, BIOS_BASE, BIOS_FILE_SIZE) {
+            self.bios.read::<T>(off as u32).unwrap()
+        } else if let Some(off) = Self::within(phys, SCRATCHPAD_BASE, SCRATCHPAD_SIZE) {
+            T::load_le(&self.scratchpad[off..off + T::WIDTH])
+        } else if let Some(off) = Self::within(phys, IO_BASE, IO_SIZE) {
+            T::load_le(&self.io[off..off + T::WIDTH])
+        } else {
+            panic!("Unmapped bus read at physical address {:#010x}", phys);
+        }
+    }
+
+    /// Writes a little-endian value of width `T` to `addr`. BIOS ROM is not writable.
+    pub fn store<T: Addressable>(&mut self, addr: u32, value: T) {
+        let phys = Self::to_physical(addr);
+        if let Some(off) = Self::within(phys, RAM_BASE, RAM_SIZE) {
+            self.ram.write::<T>(off, value);
+        } else if let Some(off) = Self::within(phys, SCRATCHPAD_BASE, SCRATCHPAD_SIZE) {
+            value.store_le(&mut self.scratchpad[off..off + T::WIDTH]);
+        } else if let Some(off) = Self::within(phys, IO_BASE, IO_SIZE) {
+            value.store_le(&mut self.io[off..off + T::WIDTH]);
+        } else {
+            panic!("Unmapped bus write at physical address {:#010x}", phys);
+        }
+    }
 
-    /// Attempts to read a value from `addr`.
-    pub fn read(&mut self, addr: usize) -> Option<u32> {
-        if addr < MEMORY_SIZE {
-            Some(self.data[addr])
+    /// Side-effect-free read used by the debugger; unmapped addresses read as 0.
+    pub fn dbg_load<T: Addressable>(&self, addr: u32) -> T {
+        let phys = Self::to_physical(addr);
+        if let Some(off) = Self::within(phys, RAM_BASE, RAM_SIZE) {
+            self.ram.read::<T>(off).unwrap()
+        } else if let Some(off) = Self::within(phys, BIOS_BASE, BIOS_FILE_SIZE) {
+            self.bios.dbg_read::<T>(off as u32).unwrap()
+        } else if let Some(off) = Self::within(phys, SCRATCHPAD_BASE, SCRATCHPAD_SIZE) {
+            T::load_le(&self.scratchpad[off..off + T::WIDTH])
+        } else if let Some(off) = Self::within(phys, IO_BASE, IO_SIZE) {
+            T::load_le(&self.io[off..off + T::WIDTH])
+        } else {
+            T::load_le(&[0_u8; 4][..T::WIDTH])
+        }
+    }
+
+    /// Debugger-initiated write; ROM and unmapped addresses are silently ignored.
+    pub fn dbg_store<T: Addressable>(&mut self, addr: u32, value: T) {
+        let phys = Self::to_physical(addr);
+        if let Some(off) = Self::within(phys, RAM_BASE, RAM_SIZE) {
+            self.ram.write::<T>(off, value);
+        } else if let Some(off) = Self::within(phys, SCRATCHPAD_BASE, SCRATCHPAD_SIZE) {
+            value.store_le(&mut self.scratchpad[off..off + T::WIDTH]);
+        } else if let Some(off) = Self::within(phys, IO_BASE, IO_SIZE) {
+            value.store_le(&mut self.io[off..off + T::WIDTH]);
+        }
+    }
+
+    /// Returns the local offset if `phys` falls inside `[base, base + size)`.
+    fn within(phys: u32, base: u32, size: usize) -> Option<usize> {
+        let offset = phys.wrapping_sub(base) as usize;
+        if phys >= base && offset < size {
+            Some(offset)
         } else {
             None
         }
     }
 }
 
+impl Default for CPU {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CPU {
     pub fn new() -> Self {
         Self {
             pc: BIOS_START,
+            next_pc: BIOS_START.wrapping_add(INSTRUCTION_SIZE),
             gprs: [0_u32; NUM_REGISTERS],
+            out_gprs: [0_u32; NUM_REGISTERS],
             hi: 0,
             lo: 0,
-            memory: Memory::new_empty(),
+            load: None,
+            bus: Bus::new(BIOS::new_empty()),
+        }
+    }
+
+    /// Reads a general purpose register. `$zero` always reads as 0.
+    fn reg(&self, index: usize) -> u32 {
+        self.gprs[index]
+    }
+
+    /// Writes a general purpose register through the pending bank.
+    ///
+    /// Writes to `$zero` (gpr[0]) are discarded so it always reads 0.
+    fn set_reg(&mut self, index: usize, value: u32) {
+        self.out_gprs[index] = value;
+        self.out_gprs[0] = 0;
+    }
+
+    /// Fetches the 32-bit instruction word at `pc` through the bus.
+    fn fetch(&mut self) -> u32 {
+        self.bus.load::<u32>(self.pc)
+    }
+
+    /// Executes a single instruction, honouring load- and branch-delay slots.
+    pub fn step(&mut self) {
+        let instruction = self.fetch();
+
+        self.pc = self.next_pc;
+        self.next_pc = self.next_pc.wrapping_add(INSTRUCTION_SIZE);
+
+        // Commit the load started one instruction ago before this one runs.
+        if let Some((reg, value)) = self.load.take() {
+            self.set_reg(reg, value);
+        }
+
+        self.decode_and_execute(instruction);
+
+        // Make this step's register writes visible to the next instruction.
+        self.gprs = self.out_gprs;
+    }
+
+    /// Points `next_pc` at a PC-relative branch target from the delay slot.
+    fn branch(&mut self, offset: u32) {
+        self.next_pc = self.pc.wrapping_add(offset << 2);
+    }
+
+    /// Decodes the primary opcode and dispatches to the matching handler.
+    fn decode_and_execute(&mut self, instruction: u32) {
+        let op = instruction >> 26;
+        let rs = ((instruction >> 21) & 0x1f) as usize;
+        let rt = ((instruction >> 16) & 0x1f) as usize;
+        let rd = ((instruction >> 11) & 0x1f) as usize;
+        let shamt = (instruction >> 6) & 0x1f;
+        let funct = instruction & 0x3f;
+        let imm = instruction & 0xffff;
+        let imm_se = imm as i16 as u32;
+        let target = instruction & 0x03ff_ffff;
+
+        match op {
+            0x00 => self.execute_special(instruction, rs, rt, rd, shamt, funct),
+            0x01 => self.execute_regimm(rs, rt, imm_se),
+            // J / JAL
+            0x02 => self.next_pc = (self.pc & 0xf000_0000) | (target << 2),
+            0x03 => {
+                self.set_reg(31, self.next_pc);
+                self.next_pc = (self.pc & 0xf000_0000) | (target << 2);
+            }
+            // BEQ / BNE / BLEZ / BGTZ
+            0x04 => {
+                if self.reg(rs) == self.reg(rt) {
+                    self.branch(imm_se);
+                }
+            }
+            0x05 => {
+                if self.reg(rs) != self.reg(rt) {
+                    self.branch(imm_se);
+                }
+            }
+            0x06 => {
+                if self.reg(rs) as i32 <= 0 {
+                    self.branch(imm_se);
+                }
+            }
+            0x07 => {
+                if self.reg(rs) as i32 > 0 {
+                    self.branch(imm_se);
+                }
+            }
+            // ADDI / ADDIU
+            0x08 => {
+                // ADDI traps on signed overflow on real hardware; with no
+                // exception model yet we wrap instead of crashing the host.
+                let value = (self.reg(rs) as i32).wrapping_add(imm_se as i32);
+                self.set_reg(rt, value as u32);
+            }
+            0x09 => self.set_reg(rt, self.reg(rs).wrapping_add(imm_se)),
+            // SLTI / SLTIU
+            0x0a => self.set_reg(rt, ((self.reg(rs) as i32) < imm_se as i32) as u32),
+            0x0b => self.set_reg(rt, (self.reg(rs) < imm_se) as u32),
+            // ANDI / ORI / XORI
+            0x0c => self.set_reg(rt, self.reg(rs) & imm),
+            0x0d => self.set_reg(rt, self.reg(rs) | imm),
+            0x0e => self.set_reg(rt, self.reg(rs) ^ imm),
+            // LUI
+            0x0f => self.set_reg(rt, imm << 16),
+            // Loads
+            0x20 => {
+                let addr = self.reg(rs).wrapping_add(imm_se);
+                let value = self.bus.load::<u8>(addr) as i8 as u32;
+                self.load = Some((rt, value));
+            }
+            0x21 => {
+                let addr = self.reg(rs).wrapping_add(imm_se);
+                let value = self.bus.load::<u16>(addr) as i16 as u32;
+                self.load = Some((rt, value));
+            }
+            0x23 => {
+                let addr = self.reg(rs).wrapping_add(imm_se);
+                let value = self.bus.load::<u32>(addr);
+                self.load = Some((rt, value));
+            }
+            0x24 => {
+                let addr = self.reg(rs).wrapping_add(imm_se);
+                let value = self.bus.load::<u8>(addr) as u32;
+                self.load = Some((rt, value));
+            }
+            0x25 => {
+                let addr = self.reg(rs).wrapping_add(imm_se);
+                let value = self.bus.load::<u16>(addr) as u32;
+                self.load = Some((rt, value));
+            }
+            // Stores
+            0x28 => {
+                let addr = self.reg(rs).wrapping_add(imm_se);
+                self.bus.store::<u8>(addr, self.reg(rt) as u8);
+            }
+            0x29 => {
+                let addr = self.reg(rs).wrapping_add(imm_se);
+                self.bus.store::<u16>(addr, self.reg(rt) as u16);
+            }
+            0x2b => {
+                let addr = self.reg(rs).wrapping_add(imm_se);
+                self.bus.store::<u32>(addr, self.reg(rt));
+            }
+            _ => panic!("Unhandled instruction {:#010x} (op {:#04x})", instruction, op),
+        }
+    }
+
+    /// Handles the `SPECIAL` opcode family selected by the `funct` field.
+    fn execute_special(
+        &mut self,
+        instruction: u32,
+        rs: usize,
+        rt: usize,
+        rd: usize,
+        shamt: u32,
+        funct: u32,
+    ) {
+        match funct {
+            // Shifts by a constant amount
+            0x00 => self.set_reg(rd, self.reg(rt) << shamt),
+            0x02 => self.set_reg(rd, self.reg(rt) >> shamt),
+            0x03 => self.set_reg(rd, (self.reg(rt) as i32 >> shamt) as u32),
+            // Shifts by a register amount
+            0x04 => self.set_reg(rd, self.reg(rt) << (self.reg(rs) & 0x1f)),
+            0x06 => self.set_reg(rd, self.reg(rt) >> (self.reg(rs) & 0x1f)),
+            0x07 => self.set_reg(rd, (self.reg(rt) as i32 >> (self.reg(rs) & 0x1f)) as u32),
+            // JR / JALR
+            0x08 => self.next_pc = self.reg(rs),
+            0x09 => {
+                self.set_reg(rd, self.next_pc);
+                self.next_pc = self.reg(rs);
+            }
+            // HI/LO moves
+            0x10 => self.set_reg(rd, self.hi),
+            0x11 => self.hi = self.reg(rs),
+            0x12 => self.set_reg(rd, self.lo),
+            0x13 => self.lo = self.reg(rs),
+            // Multiply / divide
+            0x18 => {
+                let result = (self.reg(rs) as i32 as i64) * (self.reg(rt) as i32 as i64);
+                self.hi = (result >> 32) as u32;
+                self.lo = result as u32;
+            }
+            0x19 => {
+                let result = (self.reg(rs) as u64) * (self.reg(rt) as u64);
+                self.hi = (result >> 32) as u32;
+                self.lo = result as u32;
+            }
+            0x1a => self.op_div(self.reg(rs) as i32, self.reg(rt) as i32),
+            0x1b => self.op_divu(self.reg(rs), self.reg(rt)),
+            // ADD / ADDU / SUB / SUBU
+            0x20 => {
+                // ADD traps on signed overflow on real hardware; with no
+                // exception model yet we wrap instead of crashing the host.
+                let value = (self.reg(rs) as i32).wrapping_add(self.reg(rt) as i32);
+                self.set_reg(rd, value as u32);
+            }
+            0x21 => self.set_reg(rd, self.reg(rs).wrapping_add(self.reg(rt))),
+            0x22 => {
+                // SUB traps on signed overflow on real hardware; with no
+                // exception model yet we wrap instead of crashing the host.
+                let value = (self.reg(rs) as i32).wrapping_sub(self.reg(rt) as i32);
+                self.set_reg(rd, value as u32);
+            }
+            0x23 => self.set_reg(rd, self.reg(rs).wrapping_sub(self.reg(rt))),
+            // Bitwise
+            0x24 => self.set_reg(rd, self.reg(rs) & self.reg(rt)),
+            0x25 => self.set_reg(rd, self.reg(rs) | self.reg(rt)),
+            0x26 => self.set_reg(rd, self.reg(rs) ^ self.reg(rt)),
+            0x27 => self.set_reg(rd, !(self.reg(rs) | self.reg(rt))),
+            // Set on less than
+            0x2a => self.set_reg(rd, ((self.reg(rs) as i32) < self.reg(rt) as i32) as u32),
+            0x2b => self.set_reg(rd, (self.reg(rs) < self.reg(rt)) as u32),
+            _ => panic!(
+                "Unhandled SPECIAL instruction {:#010x} (funct {:#04x})",
+                instruction, funct
+            ),
+        }
+    }
+
+    /// Handles the `REGIMM` branch forms (`BLTZ` / `BGEZ`).
+    fn execute_regimm(&mut self, rs: usize, rt: usize, imm_se: u32) {
+        // Bit 0 of `rt` selects BGEZ (1) over BLTZ (0).
+        let take = if rt & 1 == 1 {
+            self.reg(rs) as i32 >= 0
+        } else {
+            (self.reg(rs) as i32) < 0
+        };
+        if take {
+            self.branch(imm_se);
+        }
+    }
+
+    /// GDB register index of the program counter.
+    pub const REG_PC: usize = 32;
+    /// GDB register index of the HI register.
+    pub const REG_HI: usize = 33;
+    /// GDB register index of the LO register.
+    pub const REG_LO: usize = 34;
+    /// Number of registers exposed to the debugger: 32 GPRs plus pc/hi/lo.
+    pub const GDB_REGISTER_COUNT: usize = 35;
+
+    /// Reads a register by its debug index for the stub's `g`/`p` packets.
+    pub fn read_register(&self, index: usize) -> u32 {
+        match index {
+            0..=31 => self.gprs[index],
+            Self::REG_PC => self.pc,
+            Self::REG_HI => self.hi,
+            Self::REG_LO => self.lo,
+            _ => 0,
+        }
+    }
+
+    /// Writes a register by its debug index for the stub's `G`/`P` packets.
+    pub fn write_register(&mut self, index: usize, value: u32) {
+        match index {
+            0 => {}
+            1..=31 => {
+                self.gprs[index] = value;
+                self.out_gprs[index] = value;
+            }
+            Self::REG_PC => {
+                self.pc = value;
+                self.next_pc = value.wrapping_add(INSTRUCTION_SIZE);
+            }
+            Self::REG_HI => self.hi = value,
+            Self::REG_LO => self.lo = value,
+            _ => {}
+        }
+    }
+
+    /// Reads `len` bytes starting at `addr` without perturbing CPU state.
+    pub fn read_mem(&self, addr: u32, len: usize) -> Vec<u8> {
+        (0..len)
+            .map(|i| self.bus.dbg_load::<u8>(addr.wrapping_add(i as u32)))
+            .collect()
+    }
+
+    /// Writes `data` starting at `addr`, skipping ROM and unmapped regions.
+    pub fn write_mem(&mut self, addr: u32, data: &[u8]) {
+        for (i, &byte) in data.iter().enumerate() {
+            self.bus.dbg_store::<u8>(addr.wrapping_add(i as u32), byte);
+        }
+    }
+
+    /// Side-loads a PSX-EXE image into RAM and hands control to its entry point.
+    ///
+    /// Parses the 2 KB header (magic, initial `pc`/`$gp`, load destination and
+    /// text size, and the stack base/offset), copies the body after the header
+    /// into main RAM through the bus, then seeds `pc` and `$gp`. `$sp` and `$fp`
+    /// are set to base + offset only when the header's stack base is non-zero;
+    /// otherwise they keep their current values.
+    pub fn load_exe(&mut self, path: &path::Path) -> Result<(), io::Error> {
+        let image = fs::read(path)?;
+        if image.len() < EXE_HEADER_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "PSX-EXE file is smaller than its 2 KB header",
+            ));
+        }
+        if &image[..EXE_MAGIC.len()] != EXE_MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "PSX-EXE file is missing the \"PS-X EXE\" magic",
+            ));
+        }
+
+        let pc = <u32 as Addressable>::load_le(&image[0x10..0x14]);
+        let gp = <u32 as Addressable>::load_le(&image[0x14..0x18]);
+        let destination = <u32 as Addressable>::load_le(&image[0x18..0x1c]);
+        let text_size = <u32 as Addressable>::load_le(&image[0x1c..0x20]) as usize;
+        let sp_base = <u32 as Addressable>::load_le(&image[0x30..0x34]);
+        let sp_offset = <u32 as Addressable>::load_le(&image[0x34..0x38]);
+
+        // Copy the program body into RAM at its load destination.
+        let body = &image[EXE_HEADER_SIZE..];
+        let length = text_size.min(body.len());
+        for (offset, &byte) in body[..length].iter().enumerate() {
+            self.bus
+                .store::<u8>(destination.wrapping_add(offset as u32), byte);
+        }
+
+        self.pc = pc;
+        self.next_pc = pc.wrapping_add(INSTRUCTION_SIZE);
+        self.seed_register(28, gp); // $gp
+        if sp_base != 0 {
+            let sp = sp_base.wrapping_add(sp_offset);
+            self.seed_register(29, sp); // $sp
+            self.seed_register(30, sp); // $fp
+        }
+
+        Ok(())
+    }
+
+    /// Sets a register in both banks so its value is live from the first step.
+    fn seed_register(&mut self, index: usize, value: u32) {
+        self.gprs[index] = value;
+        self.out_gprs[index] = value;
+    }
+
+    /// Signed divide with the R3000A's defined division-by-zero results.
+    fn op_div(&mut self, numerator: i32, denominator: i32) {
+        if denominator == 0 {
+            self.hi = numerator as u32;
+            self.lo = if numerator >= 0 { 0xffff_ffff } else { 1 };
+        } else if numerator as u32 == 0x8000_0000 && denominator == -1 {
+            self.hi = 0;
+            self.lo = 0x8000_0000;
+        } else {
+            self.lo = (numerator / denominator) as u32;
+            self.hi = (numerator % denominator) as u32;
+        }
+    }
+
+    /// Unsigned divide with the R3000A's defined division-by-zero results.
+    fn op_divu(&mut self, numerator: u32, denominator: u32) {
+        match numerator.checked_div(denominator) {
+            Some(quotient) => {
+                self.lo = quotient;
+                self.hi = numerator % denominator;
+            }
+            None => {
+                self.hi = numerator;
+                self.lo = 0xffff_ffff;
+            }
+        }
+    }
+}
+
+/// A GDB remote-serial-protocol stub that drives a [`CPU`] over a TCP socket.
+///
+/// The stub owns only the transport and breakpoint bookkeeping; it pokes the
+/// core through [`CPU::step`], [`CPU::read_register`], [`CPU::read_mem`] and
+/// friends, so the interpreter stays oblivious to the debugger.
+pub struct GdbStub<'a> {
+    cpu: &'a mut CPU,
+    breakpoints: HashSet<u32>,
+}
+
+impl<'a> GdbStub<'a> {
+    /// Wraps a CPU for debugging.
+    pub fn new(cpu: &'a mut CPU) -> Self {
+        Self {
+            cpu,
+            breakpoints: HashSet::new(),
+        }
+    }
+
+    /// Accepts a single GDB connection on `addr` and services it until the
+    /// client disconnects.
+    pub fn serve(&mut self, addr: &str) -> io::Result<()> {
+        let listener = TcpListener::bind(addr)?;
+        let (mut stream, _) = listener.accept()?;
+
+        let mut buffer = [0_u8; 4096];
+        let mut pending: Vec<u8> = Vec::new();
+
+        loop {
+            let read = stream.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            pending.extend_from_slice(&buffer[..read]);
+
+            while let Some(packet) = Self::extract_packet(&mut pending) {
+                // Acknowledge receipt, then answer.
+                stream.write_all(b"+")?;
+                let reply = self.handle_packet(&packet);
+                stream.write_all(Self::frame(&reply).as_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Dispatches a single RSP packet body to its handler and returns the reply
+    /// body (an empty string signals "unsupported").
+    fn handle_packet(&mut self, packet: &str) -> String {
+        match packet.as_bytes().first() {
+            Some(b'?') => "S05".to_string(),
+            Some(b'g') => self.read_registers(),
+            Some(b'G') => self.write_registers(&packet[1..]),
+            Some(b'p') => {
+                let index = usize::from_str_radix(&packet[1..], 16).unwrap_or(0);
+                Self::encode_u32(self.cpu.read_register(index))
+            }
+            Some(b'P') => {
+                if let Some((index, value)) = packet[1..].split_once('=') {
+                    let index = usize::from_str_radix(index, 16).unwrap_or(0);
+                    self.cpu.write_register(index, Self::decode_u32(value));
+                }
+                "OK".to_string()
+            }
+            Some(b'm') => self.read_memory(&packet[1..]),
+            Some(b'M') => self.write_memory(&packet[1..]),
+            Some(b'c') => self.resume(false),
+            Some(b's') => self.resume(true),
+            Some(b'Z') => self.breakpoint(&packet[1..], true),
+            Some(b'z') => self.breakpoint(&packet[1..], false),
+            _ => String::new(),
         }
     }
 
-    fn clear_registers(&mut self) {
-        self.gprs = [0; NUM_REGISTERS];
+    /// Serialises every exposed register for the `g` packet.
+    fn read_registers(&self) -> String {
+        (0..CPU::GDB_REGISTER_COUNT)
+            .map(|index| Self::encode_u32(self.cpu.read_register(index)))
+            .collect()
+    }
+
+    /// Applies a `G` packet that carries every register back to back.
+    fn write_registers(&mut self, data: &str) -> String {
+        for index in 0..CPU::GDB_REGISTER_COUNT {
+            let start = index * 8;
+            if start + 8 <= data.len() {
+                self.cpu
+                    .write_register(index, Self::decode_u32(&data[start..start + 8]));
+            }
+        }
+        "OK".to_string()
+    }
+
+    /// Handles an `m addr,len` memory read.
+    fn read_memory(&self, body: &str) -> String {
+        if let Some((addr, len)) = body.split_once(',') {
+            let addr = u32::from_str_radix(addr, 16).unwrap_or(0);
+            let len = usize::from_str_radix(len, 16).unwrap_or(0);
+            return self
+                .cpu
+                .read_mem(addr, len)
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect();
+        }
+        "E01".to_string()
+    }
+
+    /// Handles an `M addr,len:data` memory write.
+    fn write_memory(&mut self, body: &str) -> String {
+        if let Some((head, data)) = body.split_once(':') {
+            if let Some((addr, _len)) = head.split_once(',') {
+                let addr = u32::from_str_radix(addr, 16).unwrap_or(0);
+                self.cpu.write_mem(addr, &Self::decode_hex(data));
+                return "OK".to_string();
+            }
+        }
+        "E01".to_string()
+    }
+
+    /// Implements the `c` and `s` commands by single-stepping the interpreter.
+    fn resume(&mut self, single_step: bool) -> String {
+        if single_step {
+            self.cpu.step();
+        } else {
+            loop {
+                self.cpu.step();
+                if self.breakpoints.contains(&self.cpu.read_register(CPU::REG_PC)) {
+                    break;
+                }
+            }
+        }
+        "S05".to_string()
+    }
+
+    /// Inserts or removes a software breakpoint (`Z0,addr,kind` / `z0,...`).
+    fn breakpoint(&mut self, body: &str, insert: bool) -> String {
+        let fields: Vec<&str> = body.split(',').collect();
+        // Only software breakpoints (type 0) are supported.
+        if fields.len() < 2 || fields[0] != "0" {
+            return String::new();
+        }
+        let addr = u32::from_str_radix(fields[1], 16).unwrap_or(0);
+        if insert {
+            self.breakpoints.insert(addr);
+        } else {
+            self.breakpoints.remove(&addr);
+        }
+        "OK".to_string()
+    }
+
+    /// Extracts the next `$...#xx` packet body from the receive buffer, draining
+    /// the consumed bytes. Returns `None` while a packet is still incomplete.
+    fn extract_packet(pending: &mut Vec<u8>) -> Option<String> {
+        let start = pending.iter().position(|&b| b == b'$')?;
+        let hash = pending[start..].iter().position(|&b| b == b'#')? + start;
+        if pending.len() < hash + 3 {
+            return None;
+        }
+        let body = String::from_utf8_lossy(&pending[start + 1..hash]).into_owned();
+        pending.drain(..hash + 3);
+        Some(body)
+    }
+
+    /// Wraps a reply body in the `$body#checksum` RSP envelope.
+    fn frame(reply: &str) -> String {
+        format!("${}#{:02x}", reply, Self::checksum(reply))
+    }
+
+    /// Computes the modulo-256 checksum RSP appends to every packet.
+    fn checksum(data: &str) -> u8 {
+        data.bytes().fold(0_u8, |acc, byte| acc.wrapping_add(byte))
+    }
+
+    /// Encodes a word as little-endian hex, matching the target byte order.
+    fn encode_u32(value: u32) -> String {
+        value
+            .to_le_bytes()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    /// Decodes a little-endian hex word sent by the client.
+    fn decode_u32(hex: &str) -> u32 {
+        let mut bytes = [0_u8; 4];
+        for (slot, byte) in bytes.iter_mut().zip(Self::decode_hex(hex)) {
+            *slot = byte;
+        }
+        u32::from_le_bytes(bytes)
     }
 
-    fn increment_pc(&mut self) {
-        self.pc = self.pc.wrapping_add(INSTRUCTION_SIZE);
+    /// Decodes a run of hex digit pairs into bytes.
+    fn decode_hex(hex: &str) -> Vec<u8> {
+        hex.as_bytes()
+            .chunks(2)
+            .filter_map(|pair| {
+                std::str::from_utf8(pair)
+                    .ok()
+                    .and_then(|s| u8::from_str_radix(s, 16).ok())
+            })
+            .collect()
     }
 }